@@ -14,6 +14,8 @@ fn test_fn() {
     type t = @fn() -> int;
     fn ten() -> int { return 10; }
     let rs: t = { ten };
+    // blocked on typeck/trans coercion of a bare fn item to a boxed
+    // @fn closure in tail position; enable once that lands
     //assert (rs() == 10);
 }
 